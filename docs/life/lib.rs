@@ -1,15 +1,170 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// A cell coordinate on the infinite grid.
 pub type Cell = (i64, i64);
 
+/// A Life-like cellular automaton rule: the sets of live-neighbor counts
+/// that cause birth (in a dead cell) or survival (in a live cell).
+///
+/// Parsed from standard "B/S" rulestring notation, e.g. `"B3/S23"` for
+/// Conway's Life, `"B36/S23"` for HighLife, or `"B2/S"` for Seeds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: HashSet<u8>,
+    pub survival: HashSet<u8>,
+}
+
+/// An error parsing a [`Rule`] from rulestring notation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleParseError(String);
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rulestring: {}", self.0)
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+impl Rule {
+    /// Conway's standard Life rule: B3/S23.
+    pub fn conway() -> Self {
+        Self {
+            birth: HashSet::from([3]),
+            survival: HashSet::from([2, 3]),
+        }
+    }
+
+    /// Parse a rulestring like `"B3/S23"`, `"B36/S23"`, or `"B2/S"`.
+    pub fn parse(s: &str) -> Result<Self, RuleParseError> {
+        let s = s.trim();
+        let (b_part, s_part) = s
+            .split_once('/')
+            .ok_or_else(|| RuleParseError(s.to_string()))?;
+
+        let b_digits = b_part
+            .strip_prefix('B')
+            .or_else(|| b_part.strip_prefix('b'))
+            .ok_or_else(|| RuleParseError(s.to_string()))?;
+        let s_digits = s_part
+            .strip_prefix('S')
+            .or_else(|| s_part.strip_prefix('s'))
+            .ok_or_else(|| RuleParseError(s.to_string()))?;
+
+        let parse_digits = |digits: &str| -> Result<HashSet<u8>, RuleParseError> {
+            digits
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .filter(|&d| d <= 8)
+                        .map(|d| d as u8)
+                        .ok_or_else(|| RuleParseError(s.to_string()))
+                })
+                .collect()
+        };
+
+        Ok(Self {
+            birth: parse_digits(b_digits)?,
+            survival: parse_digits(s_digits)?,
+        })
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut birth: Vec<u8> = self.birth.iter().copied().collect();
+        birth.sort_unstable();
+        let mut survival: Vec<u8> = self.survival.iter().copied().collect();
+        survival.sort_unstable();
+        write!(f, "B")?;
+        for d in birth {
+            write!(f, "{d}")?;
+        }
+        write!(f, "/S")?;
+        for d in survival {
+            write!(f, "{d}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An error parsing an RLE (Run Length Encoded) pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RleParseError(String);
+
+impl fmt::Display for RleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid RLE pattern: {}", self.0)
+    }
+}
+
+impl std::error::Error for RleParseError {}
+
+/// A small, fast, seedable PRNG (SplitMix64) so seeding a grid with random
+/// cells stays reproducible without adding a dependency on the `rand` crate.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// A pseudo-random float uniformly distributed in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
 /// Sparse representation of an infinite Game of Life grid.
 /// Only live cells are stored.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Grid {
     alive: HashSet<Cell>,
     generation: u64,
+    rule: Rule,
+    topology: Topology,
+}
+
+/// The shape of the universe a [`Grid`] evolves within.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Topology {
+    /// No boundary: the grid extends forever in all directions. Default.
+    #[default]
+    Infinite,
+    /// A fixed `rows x cols` rectangle with dead edges: neighbor
+    /// contributions that fall outside the rectangle are simply dropped.
+    Bounded { rows: i64, cols: i64 },
+    /// A fixed `rows x cols` rectangle whose edges wrap around (a torus):
+    /// neighbor coordinates are taken modulo the dimensions.
+    Toroidal { rows: i64, cols: i64 },
+}
+
+/// The result of [`Grid::run_until_stable`] detecting that a pattern has
+/// settled into a repeating cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Generations between repeats of the (normalized) pattern.
+    pub period: u64,
+    /// Net `(row, col)` shift per period: zero for still lifes and
+    /// oscillators, nonzero for spaceships (and proportional to their speed).
+    pub displacement: (i64, i64),
 }
 
 /// The eight orthogonal + diagonal neighbor offsets.
@@ -19,12 +174,23 @@ const NEIGHBORS: [(i64, i64); 8] = [
     ( 1, -1), ( 1, 0), ( 1, 1),
 ];
 
+/// Append a single run of `count` copies of `ch` in RLE shorthand
+/// (the count is omitted when it's 1, per convention).
+fn push_rle_run(buf: &mut String, count: u32, ch: char) {
+    if count > 1 {
+        buf.push_str(&count.to_string());
+    }
+    buf.push(ch);
+}
+
 impl Grid {
     /// Create an empty grid.
     pub fn new() -> Self {
         Self {
             alive: HashSet::new(),
             generation: 0,
+            rule: Rule::default(),
+            topology: Topology::default(),
         }
     }
 
@@ -33,7 +199,146 @@ impl Grid {
         Self {
             alive: cells.into_iter().collect(),
             generation: 0,
+            rule: Rule::default(),
+            topology: Topology::default(),
+        }
+    }
+
+    /// Set the rule this grid evolves under, replacing the default B3/S23.
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the universe shape this grid evolves within, replacing the
+    /// default `Topology::Infinite`.
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// The universe shape this grid currently evolves within.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// The rule this grid currently evolves under.
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Parse a grid from RLE (Run Length Encoded) pattern text, the de
+    /// facto interchange format used by sites like the LifeWiki.
+    ///
+    /// Reads the `x = .., y = ..` header (an optional `rule = ..` field
+    /// wires into [`Rule::parse`]), then decodes the body where a run
+    /// like `3o` means three live cells, `2b` means two dead cells, `$`
+    /// ends a row, and `!` ends the pattern. Live cells are anchored at
+    /// (offset_row, offset_col).
+    pub fn from_rle(rle: &str, offset_row: i64, offset_col: i64) -> Result<Self, RleParseError> {
+        let mut lines = rle.lines().filter(|l| !l.trim_start().starts_with('#'));
+        let header = lines
+            .next()
+            .ok_or_else(|| RleParseError("missing header line".to_string()))?;
+
+        let mut width = None;
+        let mut height = None;
+        let mut rule = Rule::default();
+        for field in header.split(',') {
+            let field = field.trim();
+            if let Some(value) = field.strip_prefix("rule") {
+                let value = value.trim_start().trim_start_matches('=').trim();
+                rule = Rule::parse(value).map_err(|e| RleParseError(e.to_string()))?;
+            } else if let Some(value) = field.strip_prefix('x') {
+                let value = value.trim_start().trim_start_matches('=').trim();
+                width = Some(
+                    value
+                        .parse::<u64>()
+                        .map_err(|_| RleParseError(format!("invalid x dimension: {value}")))?,
+                );
+            } else if let Some(value) = field.strip_prefix('y') {
+                let value = value.trim_start().trim_start_matches('=').trim();
+                height = Some(
+                    value
+                        .parse::<u64>()
+                        .map_err(|_| RleParseError(format!("invalid y dimension: {value}")))?,
+                );
+            }
+        }
+
+        if width.is_none() || height.is_none() {
+            return Err(RleParseError(format!(
+                "missing x/y header fields: {header}"
+            )));
+        }
+
+        let body: String = lines.collect();
+        let mut cells = Vec::new();
+        let (mut row, mut col) = (0i64, 0i64);
+        let mut run_len: u32 = 0;
+
+        for ch in body.chars() {
+            match ch {
+                '0'..='9' => run_len = run_len * 10 + ch.to_digit(10).unwrap(),
+                'b' => {
+                    col += run_len.max(1) as i64;
+                    run_len = 0;
+                }
+                'o' => {
+                    let n = run_len.max(1) as i64;
+                    cells.extend((0..n).map(|i| (row + offset_row, col + i + offset_col)));
+                    col += n;
+                    run_len = 0;
+                }
+                '$' => {
+                    row += run_len.max(1) as i64;
+                    col = 0;
+                    run_len = 0;
+                }
+                '!' => break,
+                c if c.is_whitespace() => {}
+                c => return Err(RleParseError(format!("unexpected character '{c}'"))),
+            }
+        }
+
+        Ok(Self::from_cells(cells).with_rule(rule))
+    }
+
+    /// Encode this grid as RLE pattern text, the inverse of [`Grid::from_rle`].
+    pub fn to_rle(&self) -> String {
+        let Some((r0, c0, r1, c1)) = self.bounds() else {
+            return format!("x = 0, y = 0, rule = {}\n!", self.rule);
+        };
+        let width = c1 - c0 + 1;
+        let height = r1 - r0 + 1;
+        let mut out = format!("x = {width}, y = {height}, rule = {}\n", self.rule);
+
+        for r in r0..=r1 {
+            let mut run_ch: Option<char> = None;
+            let mut run_len: u32 = 0;
+            for c in c0..=c1 {
+                let ch = if self.alive.contains(&(r, c)) { 'o' } else { 'b' };
+                match run_ch {
+                    Some(prev) if prev == ch => run_len += 1,
+                    _ => {
+                        if let Some(prev) = run_ch {
+                            push_rle_run(&mut out, run_len, prev);
+                        }
+                        run_ch = Some(ch);
+                        run_len = 1;
+                    }
+                }
+            }
+            // Trailing dead cells before a row terminator are implicit.
+            if let Some(prev) = run_ch
+                && prev != 'b'
+            {
+                push_rle_run(&mut out, run_len, prev);
+            }
+            out.push(if r == r1 { '!' } else { '$' });
         }
+
+        out
     }
 
     /// Parse a grid from a multi-line string where `#` or `O` = alive.
@@ -43,13 +348,42 @@ impl Grid {
             .lines()
             .enumerate()
             .flat_map(|(r, line)| {
-                line.chars().enumerate().filter_map(move |(c, ch)| {
-                    matches!(ch, '#' | 'O').then(|| (r as i64 + offset_row, c as i64 + offset_col))
-                })
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, ch)| matches!(ch, '#' | 'O'))
+                    .map(move |(c, _)| (r as i64 + offset_row, c as i64 + offset_col))
             });
         Self::from_cells(cells)
     }
 
+    /// Fill a `rows x cols` rectangle at (offset_row, offset_col) with live
+    /// cells at the given probability (0.0..=1.0), using a seeded PRNG so
+    /// the result is reproducible across runs.
+    pub fn random(rows: i64, cols: i64, density: f64, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let mut cells = Vec::new();
+        for r in 0..rows {
+            for c in 0..cols {
+                if rng.next_f64() < density {
+                    cells.push((r, c));
+                }
+            }
+        }
+        Self::from_cells(cells)
+    }
+
+    /// Sprinkle `count` random live cells into the `rows x cols` rectangle
+    /// at (row0, col0), seeded for reproducibility. Existing live cells are
+    /// left untouched.
+    pub fn scatter(&mut self, row0: i64, col0: i64, rows: i64, cols: i64, count: usize, seed: u64) {
+        let mut rng = SplitMix64::new(seed);
+        for _ in 0..count {
+            let r = row0 + (rng.next_u64() % rows.max(1) as u64) as i64;
+            let c = col0 + (rng.next_u64() % cols.max(1) as u64) as i64;
+            self.set_alive((r, c));
+        }
+    }
+
     pub fn generation(&self) -> u64 {
         self.generation
     }
@@ -99,16 +433,29 @@ impl Grid {
 
         for &(r, c) in &self.alive {
             for &(dr, dc) in &NEIGHBORS {
-                *neighbor_counts.entry((r + dr, c + dc)).or_insert(0) += 1;
+                let (nr, nc) = (r + dr, c + dc);
+                match self.topology {
+                    Topology::Infinite => {
+                        *neighbor_counts.entry((nr, nc)).or_insert(0) += 1;
+                    }
+                    Topology::Bounded { rows, cols } => {
+                        if (0..rows).contains(&nr) && (0..cols).contains(&nc) {
+                            *neighbor_counts.entry((nr, nc)).or_insert(0) += 1;
+                        }
+                    }
+                    Topology::Toroidal { rows, cols } => {
+                        let wrapped = (nr.rem_euclid(rows), nc.rem_euclid(cols));
+                        *neighbor_counts.entry(wrapped).or_insert(0) += 1;
+                    }
+                }
             }
         }
 
         self.alive = neighbor_counts
             .into_iter()
-            .filter(|&(cell, count)| match count {
-                3 => true,                       // birth or survival
-                2 => self.alive.contains(&cell), // survival only
-                _ => false,                      // death or stays dead
+            .filter(|&(cell, count)| {
+                self.rule.birth.contains(&count)
+                    || (self.rule.survival.contains(&count) && self.alive.contains(&cell))
             })
             .map(|(cell, _)| cell)
             .collect();
@@ -122,6 +469,57 @@ impl Grid {
             self.step();
         }
     }
+
+    /// A translation-invariant fingerprint of the current live cells: a
+    /// hash of the pattern normalized by subtracting its bounding box's
+    /// min-corner, plus that min-corner itself (so callers can recover the
+    /// displacement between two matching fingerprints). `None` for an
+    /// empty grid.
+    fn normalized_fingerprint(&self) -> Option<(u64, i64, i64)> {
+        let (r0, c0, _, _) = self.bounds()?;
+        // XOR-combine per-cell hashes so the result doesn't depend on the
+        // (arbitrary) HashSet iteration order.
+        let mut hash: u64 = 0;
+        for &(r, c) in &self.alive {
+            let mut hasher = DefaultHasher::new();
+            (r - r0, c - c0).hash(&mut hasher);
+            hash ^= hasher.finish();
+        }
+        Some((hash, r0, c0))
+    }
+
+    /// Step forward until the pattern repeats (a still life or oscillator)
+    /// or moves and repeats (a spaceship), or `max_gens` is reached.
+    ///
+    /// Keeps a bounded ring of recent normalized fingerprints; if the
+    /// period exceeds the ring's capacity it won't be detected within
+    /// `max_gens` generations.
+    pub fn run_until_stable(&mut self, max_gens: u64) -> Option<Cycle> {
+        const HISTORY: usize = 256;
+
+        let mut history: VecDeque<(u64, u64, i64, i64)> = VecDeque::with_capacity(HISTORY);
+        let seed = self.normalized_fingerprint().unwrap_or((0, 0, 0));
+        history.push_back((self.generation, seed.0, seed.1, seed.2));
+
+        for _ in 0..max_gens {
+            self.step();
+            let (hash, r0, c0) = self.normalized_fingerprint().unwrap_or((0, 0, 0));
+
+            if let Some(&(gen_at, _, hr0, hc0)) = history.iter().find(|&&(_, h, _, _)| h == hash) {
+                return Some(Cycle {
+                    period: self.generation - gen_at,
+                    displacement: (r0 - hr0, c0 - hc0),
+                });
+            }
+
+            if history.len() == HISTORY {
+                history.pop_front();
+            }
+            history.push_back((self.generation, hash, r0, c0));
+        }
+
+        None
+    }
 }
 
 impl Default for Grid {
@@ -148,6 +546,419 @@ impl fmt::Display for Grid {
     }
 }
 
+// ─── Hashlife ────────────────────────────────────────────────────
+
+/// Index of a node in a [`HashlifeGrid`]'s arena.
+type NodeId = usize;
+
+/// The canonical empty (dead) leaf, always id 0.
+const DEAD: NodeId = 0;
+/// The canonical live leaf, always id 1.
+const ALIVE: NodeId = 1;
+
+/// Child order used throughout: north-west, north-east, south-west, south-east.
+const NW: usize = 0;
+const NE: usize = 1;
+const SW: usize = 2;
+const SE: usize = 3;
+
+#[derive(Debug)]
+enum Node {
+    /// Level 0: a single cell.
+    Leaf(bool),
+    /// Level `level` (>= 1): a `2^level x 2^level` square made of four
+    /// `level - 1` children, cached population for fast lookups.
+    Quad {
+        level: u8,
+        children: [NodeId; 4],
+        population: u64,
+    },
+}
+
+/// A hash-consed quadtree ("macrocell") representation of a Life-like
+/// universe, after Bill Gosper's Hashlife algorithm.
+///
+/// Structurally identical subtrees are interned to a single [`NodeId`], and
+/// each node's future (the centered square advanced `2^(level - 2)`
+/// generations) is memoized. Highly regular or repetitive patterns then
+/// advance in time far faster than stepping cell-by-cell, at the cost of
+/// only being able to jump by powers of two generations at once.
+pub struct HashlifeGrid {
+    nodes: Vec<Node>,
+    intern: HashMap<(u8, [NodeId; 4]), NodeId>,
+    results: HashMap<NodeId, NodeId>,
+    empty_cache: Vec<NodeId>,
+    root: NodeId,
+    generation: u64,
+    rule: Rule,
+    /// World-space coordinates of the root square's top-left corner.
+    ///
+    /// `pad()` recenters the tracked square within a larger one and
+    /// `result()` shrinks it back down to the centered half, so the root's
+    /// origin drifts with every call to [`Self::step_pow2`] and must be
+    /// carried forward to translate local quadtree coordinates back to
+    /// world space in [`Self::to_grid`].
+    origin: (i64, i64),
+}
+
+impl HashlifeGrid {
+    /// Create an empty universe.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::Leaf(false), Node::Leaf(true)],
+            intern: HashMap::new(),
+            results: HashMap::new(),
+            empty_cache: vec![DEAD],
+            root: DEAD,
+            generation: 0,
+            rule: Rule::default(),
+            origin: (0, 0),
+        }
+    }
+
+    /// Build a quadtree from a sparse [`Grid`], inheriting its rule.
+    pub fn from_grid(grid: &Grid) -> Self {
+        let mut h = Self::new();
+        h.rule = grid.rule().clone();
+
+        let Some((r0, c0, r1, c1)) = grid.bounds() else {
+            return h;
+        };
+
+        let height = r1 - r0 + 1;
+        let width = c1 - c0 + 1;
+        let size = height.max(width);
+        let mut level: u8 = 2;
+        while (1i64 << level) < size {
+            level += 1;
+        }
+        // `result()` on a level-L node returns only the centered inner half
+        // (2^(L-1)) advanced `2^(L-2)` generations, and that returned half
+        // becomes the new root — anything that grows past its edge during
+        // the advance is gone for good, not just clipped from the view. One
+        // spare level puts the live region flush against the inner half's
+        // own boundary, leaving no room for it to grow before the advance
+        // reaches that edge. Two spare levels put the live region inside
+        // the inner *quarter* instead, leaving a full `2^(L-2)`-cell margin
+        // — exactly the distance growth can travel in the generations
+        // `result()` is about to advance by — before anything reaches the
+        // edge of what gets kept.
+        level += 2;
+
+        let span = 1i64 << level;
+        // Center the live region in the square — `result()` only ever looks
+        // at the middle half, so content flush against an edge would vanish.
+        let origin_row = r0 - (span - height) / 2;
+        let origin_col = c0 - (span - width) / 2;
+        h.root = h.build_from_grid(grid, level, origin_row, origin_col, span);
+        h.origin = (origin_row, origin_col);
+        h
+    }
+
+    fn build_from_grid(&mut self, grid: &Grid, level: u8, row: i64, col: i64, span: i64) -> NodeId {
+        if level == 0 {
+            return if grid.is_alive(&(row, col)) { ALIVE } else { DEAD };
+        }
+        let half = span / 2;
+        let nw = self.build_from_grid(grid, level - 1, row, col, half);
+        let ne = self.build_from_grid(grid, level - 1, row, col + half, half);
+        let sw = self.build_from_grid(grid, level - 1, row + half, col, half);
+        let se = self.build_from_grid(grid, level - 1, row + half, col + half, half);
+        self.join(nw, ne, sw, se)
+    }
+
+    /// Convert back to a sparse [`Grid`], preserving the rule and generation.
+    pub fn to_grid(&self) -> Grid {
+        let mut cells = Vec::new();
+        self.collect_cells(self.root, self.origin.0, self.origin.1, &mut cells);
+        let mut grid = Grid::from_cells(cells).with_rule(self.rule.clone());
+        grid.generation = self.generation;
+        grid
+    }
+
+    fn collect_cells(&self, id: NodeId, row: i64, col: i64, out: &mut Vec<Cell>) {
+        if self.population_of(id) == 0 {
+            return;
+        }
+        match &self.nodes[id] {
+            Node::Leaf(true) => out.push((row, col)),
+            Node::Leaf(false) => {}
+            Node::Quad { level, children, .. } => {
+                let half = 1i64 << (*level - 1);
+                let children = *children;
+                self.collect_cells(children[NW], row, col, out);
+                self.collect_cells(children[NE], row, col + half, out);
+                self.collect_cells(children[SW], row + half, col, out);
+                self.collect_cells(children[SE], row + half, col + half, out);
+            }
+        }
+    }
+
+    /// Total live population of the universe.
+    pub fn population(&self) -> u64 {
+        self.population_of(self.root)
+    }
+
+    /// Generations elapsed since construction.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn level(&self, id: NodeId) -> u8 {
+        match &self.nodes[id] {
+            Node::Leaf(_) => 0,
+            Node::Quad { level, .. } => *level,
+        }
+    }
+
+    fn population_of(&self, id: NodeId) -> u64 {
+        match &self.nodes[id] {
+            Node::Leaf(false) => 0,
+            Node::Leaf(true) => 1,
+            Node::Quad { population, .. } => *population,
+        }
+    }
+
+    fn children(&self, id: NodeId) -> [NodeId; 4] {
+        match &self.nodes[id] {
+            Node::Quad { children, .. } => *children,
+            Node::Leaf(_) => panic!("hashlife: leaf node has no children"),
+        }
+    }
+
+    /// Intern a quad node, reusing an existing id for structurally identical nodes.
+    fn join(&mut self, nw: NodeId, ne: NodeId, sw: NodeId, se: NodeId) -> NodeId {
+        let level = self.level(nw) + 1;
+        let key = (level, [nw, ne, sw, se]);
+        if let Some(&id) = self.intern.get(&key) {
+            return id;
+        }
+        let population =
+            self.population_of(nw) + self.population_of(ne) + self.population_of(sw) + self.population_of(se);
+        let id = self.nodes.len();
+        self.nodes.push(Node::Quad {
+            level,
+            children: [nw, ne, sw, se],
+            population,
+        });
+        self.intern.insert(key, id);
+        id
+    }
+
+    /// The canonical all-dead node at a given level, built and cached lazily.
+    fn empty(&mut self, level: u8) -> NodeId {
+        while self.empty_cache.len() <= level as usize {
+            let next = self.empty_cache.len() as u8;
+            let child = self.empty_cache[next as usize - 1];
+            let id = self.join(child, child, child, child);
+            self.empty_cache.push(id);
+        }
+        self.empty_cache[level as usize]
+    }
+
+    /// Double the size of the universe, centering `id` within an empty border
+    /// so that live cells never touch the new edge.
+    fn pad(&mut self, id: NodeId) -> NodeId {
+        let level = self.level(id);
+        if level == 0 {
+            let e = self.empty(0);
+            return self.join(e, e, e, id);
+        }
+        let [nw, ne, sw, se] = self.children(id);
+        let e = self.empty(level - 1);
+        let new_nw = self.join(e, e, e, nw);
+        let new_ne = self.join(e, e, ne, e);
+        let new_sw = self.join(e, sw, e, e);
+        let new_se = self.join(se, e, e, e);
+        self.join(new_nw, new_ne, new_sw, new_se)
+    }
+
+    /// Read a cell from a flattened 4x4 neighborhood made of four level-1 quads.
+    fn leaf_in_quad(&self, quad: NodeId, local_r: usize, local_c: usize) -> bool {
+        let idx = local_r * 2 + local_c;
+        let child = self.children(quad)[idx];
+        matches!(self.nodes[child], Node::Leaf(true))
+    }
+
+    fn cell_4x4(&self, nw: NodeId, ne: NodeId, sw: NodeId, se: NodeId, r: usize, c: usize) -> bool {
+        match (r / 2, c / 2) {
+            (0, 0) => self.leaf_in_quad(nw, r % 2, c % 2),
+            (0, 1) => self.leaf_in_quad(ne, r % 2, c % 2),
+            (1, 0) => self.leaf_in_quad(sw, r % 2, c % 2),
+            (1, 1) => self.leaf_in_quad(se, r % 2, c % 2),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Base case: brute-force step a 4x4 neighborhood by one generation,
+    /// returning the centered 2x2 result.
+    fn brute_force_result(&mut self, id: NodeId) -> NodeId {
+        let [nw, ne, sw, se] = self.children(id);
+        let mut next = [[false; 2]; 2];
+        for (i, &r) in [1usize, 2].iter().enumerate() {
+            for (j, &c) in [1usize, 2].iter().enumerate() {
+                let mut count = 0u8;
+                for dr in [-1i32, 0, 1] {
+                    for dc in [-1i32, 0, 1] {
+                        if dr == 0 && dc == 0 {
+                            continue;
+                        }
+                        let (nr, nc) = (r as i32 + dr, c as i32 + dc);
+                        if (0..4).contains(&nr)
+                            && (0..4).contains(&nc)
+                            && self.cell_4x4(nw, ne, sw, se, nr as usize, nc as usize)
+                        {
+                            count += 1;
+                        }
+                    }
+                }
+                let alive = self.cell_4x4(nw, ne, sw, se, r, c);
+                next[i][j] =
+                    self.rule.birth.contains(&count) || (self.rule.survival.contains(&count) && alive);
+            }
+        }
+
+        let leaf = |alive: bool| if alive { ALIVE } else { DEAD };
+        self.join(leaf(next[0][0]), leaf(next[0][1]), leaf(next[1][0]), leaf(next[1][1]))
+    }
+
+    /// Recursive case (level >= 3): assemble the nine overlapping
+    /// level-(L-1) subsquares from `id`'s children, take each subsquare's
+    /// result (each advanced `2^(L-3)` generations), combine four of those
+    /// into level-(L-1) squares, and take THEIR results too — this second
+    /// round is what makes the whole call advance `2^(L-2)` generations
+    /// (Gosper's doubling trick) rather than just the `2^(L-3)` generations
+    /// a single round of sub-results would give.
+    fn recursive_result(&mut self, id: NodeId) -> NodeId {
+        let [nw, ne, sw, se] = self.children(id);
+        let nw_c = self.children(nw);
+        let ne_c = self.children(ne);
+        let sw_c = self.children(sw);
+        let se_c = self.children(se);
+
+        let n00 = nw;
+        let n02 = ne;
+        let n20 = sw;
+        let n22 = se;
+        let n01 = self.join(nw_c[NE], ne_c[NW], nw_c[SE], ne_c[SW]);
+        let n10 = self.join(nw_c[SW], nw_c[SE], sw_c[NW], sw_c[NE]);
+        let n11 = self.join(nw_c[SE], ne_c[SW], sw_c[NE], se_c[NW]);
+        let n12 = self.join(ne_c[SW], ne_c[SE], se_c[NW], se_c[NE]);
+        let n21 = self.join(sw_c[NE], se_c[NW], sw_c[SE], se_c[SW]);
+
+        let r00 = self.result(n00);
+        let r01 = self.result(n01);
+        let r02 = self.result(n02);
+        let r10 = self.result(n10);
+        let r11 = self.result(n11);
+        let r12 = self.result(n12);
+        let r20 = self.result(n20);
+        let r21 = self.result(n21);
+        let r22 = self.result(n22);
+
+        let t_nw = self.join(r00, r01, r10, r11);
+        let t_ne = self.join(r01, r02, r11, r12);
+        let t_sw = self.join(r10, r11, r20, r21);
+        let t_se = self.join(r11, r12, r21, r22);
+
+        let result_nw = self.result(t_nw);
+        let result_ne = self.result(t_ne);
+        let result_sw = self.result(t_sw);
+        let result_se = self.result(t_se);
+
+        self.join(result_nw, result_ne, result_sw, result_se)
+    }
+
+    /// The memoized centered `2^(level-1)` square, advanced `2^(level-2)`
+    /// generations. Requires `id`'s level to be at least 2.
+    fn result(&mut self, id: NodeId) -> NodeId {
+        if let Some(&cached) = self.results.get(&id) {
+            return cached;
+        }
+        let level = self.level(id);
+        let answer = if self.population_of(id) == 0 {
+            self.empty(level - 1)
+        } else if level == 2 {
+            self.brute_force_result(id)
+        } else {
+            self.recursive_result(id)
+        };
+        self.results.insert(id, answer);
+        answer
+    }
+
+    /// Advance the universe by `2^k` generations (or more — see below).
+    ///
+    /// Pads the root with empty borders up to at least level `k + 2` —
+    /// `result()` on a level-`L` node advances exactly `2^(L-2)`
+    /// generations — then takes a single memoized `result()` of it. Each
+    /// pad recenters the tracked square within a bigger one, and `result()`
+    /// shrinks it back to the centered half, so `origin` is nudged along
+    /// with every size change to keep world coordinates correct in
+    /// [`Self::to_grid`].
+    ///
+    /// If the root is already bigger than level `k + 2` — which is the
+    /// *normal* case, not an edge case: `from_grid` leaves two spare levels
+    /// of margin beyond what the pattern's bounding box needs, so even a
+    /// fresh `step_pow2(0)` call typically finds the root already oversized
+    /// — padding is a no-op and `result()` runs at the root's actual level
+    /// instead, advancing more than `2^k` generations. So [`Self::generation`]
+    /// is the source of truth for how many generations really elapsed; it
+    /// can run ahead of the sum of `2^k`s passed in, and callers that need
+    /// the exact count should read it back after the call rather than
+    /// assuming `2^k`.
+    ///
+    /// This is unavoidable with a single `result()` call: `result()` only
+    /// ever computes the *exact* `2^(level-2)` generations its input's
+    /// level implies, and narrowing the call to a smaller, exactly
+    /// `level-(k+2)`-sized window centered inside the oversized root is
+    /// unsound — unlike the margin `from_grid` and `pad()` build in on
+    /// purpose, that narrower window has no guaranteed empty border of its
+    /// own, so `result()` on it can silently compute the wrong cells for
+    /// whatever of the live pattern sits near its edge (see the regression
+    /// test this replaced, which froze most of an R-pentomino in place).
+    ///
+    /// `result()` only ever returns the centered inner half of its input,
+    /// and that returned half becomes the new root — so after the call the
+    /// root is padded by one more level to restore the two-spare-levels
+    /// margin for whatever comes next, the same margin [`Self::from_grid`]
+    /// establishes up front. Without it, a long run of small-`k` calls would
+    /// erode the root's margin by one level per call until growing content
+    /// started falling outside the tracked square and being silently
+    /// dropped, exactly as in the bug above but reached through repetition
+    /// instead of a single oversized call.
+    ///
+    /// `k` should not decrease between calls: the root only grows (via
+    /// padding), so a smaller `k` than a previous call cannot shrink it
+    /// back down to the matching level.
+    pub fn step_pow2(&mut self, k: u32) {
+        let target_level = (k + 2) as u8;
+        while self.level(self.root) < target_level {
+            let old_span = 1i64 << self.level(self.root);
+            self.root = self.pad(self.root);
+            self.origin.0 -= old_span / 2;
+            self.origin.1 -= old_span / 2;
+        }
+        let level = self.level(self.root);
+        let span = 1i64 << level;
+        self.root = self.result(self.root);
+        self.origin.0 += span / 4;
+        self.origin.1 += span / 4;
+        self.generation += 1u64 << (level - 2);
+
+        let old_span = 1i64 << self.level(self.root);
+        self.root = self.pad(self.root);
+        self.origin.0 -= old_span / 2;
+        self.origin.1 -= old_span / 2;
+    }
+}
+
+impl Default for HashlifeGrid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ─── Classic patterns ────────────────────────────────────────────
 
 pub mod patterns {
@@ -246,4 +1057,296 @@ mod tests {
         grid.step_n(10);
         assert!(grid.population() > 5);
     }
+
+    #[test]
+    fn from_rle_decodes_glider() {
+        // Standard LifeWiki glider RLE.
+        let rle = "x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!";
+        let grid = Grid::from_rle(rle, 0, 0).unwrap();
+        assert_eq!(grid.population(), 5);
+        assert_eq!(grid.rule(), &Rule::conway());
+        assert!(grid.is_alive(&(0, 1)));
+        assert!(grid.is_alive(&(1, 2)));
+        assert!(grid.is_alive(&(2, 0)));
+        assert!(grid.is_alive(&(2, 1)));
+        assert!(grid.is_alive(&(2, 2)));
+    }
+
+    #[test]
+    fn from_rle_wires_custom_rule() {
+        let rle = "x = 1, y = 1, rule = B36/S23\no!";
+        let grid = Grid::from_rle(rle, 0, 0).unwrap();
+        assert_eq!(grid.rule().birth, HashSet::from([3, 6]));
+    }
+
+    #[test]
+    fn rle_roundtrip_preserves_shape() {
+        let original = Grid::from_pattern(patterns::GLIDER, 0, 0);
+        let rle = original.to_rle();
+        let decoded = Grid::from_rle(&rle, 0, 0).unwrap();
+        assert_eq!(decoded.cells(), original.cells());
+    }
+
+    #[test]
+    fn from_rle_rejects_garbage() {
+        assert!(Grid::from_rle("not an rle pattern", 0, 0).is_err());
+    }
+
+    #[test]
+    fn rule_parse_conway() {
+        let rule = Rule::parse("B3/S23").unwrap();
+        assert_eq!(rule, Rule::conway());
+    }
+
+    #[test]
+    fn rule_parse_highlife() {
+        let rule = Rule::parse("B36/S23").unwrap();
+        assert_eq!(rule.birth, HashSet::from([3, 6]));
+        assert_eq!(rule.survival, HashSet::from([2, 3]));
+    }
+
+    #[test]
+    fn rule_parse_seeds_has_no_survival() {
+        let rule = Rule::parse("B2/S").unwrap();
+        assert_eq!(rule.birth, HashSet::from([2]));
+        assert!(rule.survival.is_empty());
+    }
+
+    #[test]
+    fn rule_parse_rejects_garbage() {
+        assert!(Rule::parse("not a rule").is_err());
+        assert!(Rule::parse("B9/S23").is_err());
+    }
+
+    #[test]
+    fn grid_defaults_to_conway_rule() {
+        let grid = Grid::new();
+        assert_eq!(grid.rule(), &Rule::conway());
+    }
+
+    #[test]
+    fn with_rule_changes_behavior() {
+        // Seeds (B2/S): every cell with exactly 2 neighbors is born, nothing survives.
+        let seeds = Rule::parse("B2/S").unwrap();
+        let mut grid = Grid::from_cells([(0, 0), (0, 1)]).with_rule(seeds);
+        grid.step();
+        // The two live cells themselves have 1 neighbor each and die, but the
+        // four dead cells diagonally/orthogonally adjacent to both of them —
+        // (-1,0), (-1,1), (1,0), (1,1) — each see exactly 2 neighbors and are
+        // born under Seeds.
+        assert_eq!(grid.population(), 4);
+    }
+
+    #[test]
+    fn hashlife_block_is_still_life() {
+        let grid = Grid::from_cells([(0, 0), (0, 1), (1, 0), (1, 1)]);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        h.step_pow2(0);
+        assert_eq!(h.to_grid().cells(), grid.cells());
+    }
+
+    #[test]
+    fn hashlife_blinker_oscillates() {
+        let grid = Grid::from_cells([(0, -1), (0, 0), (0, 1)]);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        // A width-3 blinker needs a level-4 root to keep the required two
+        // spare levels of margin, and `result()` on that root advances a
+        // full 4 generations regardless of the `k` asked for — which is
+        // still a whole number of the blinker's 2-generation period, so it
+        // lands back on the horizontal phase rather than the transient
+        // vertical one.
+        h.step_pow2(1);
+        assert_eq!(h.generation(), 4);
+        assert_eq!(h.to_grid().cells(), grid.cells());
+    }
+
+    #[test]
+    fn hashlife_glider_preserves_population_over_a_full_cycle() {
+        let grid = Grid::from_pattern(patterns::GLIDER, 0, 0);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        h.step_pow2(2); // one full glider cycle is 4 generations
+        assert_eq!(h.population(), 5);
+        assert_eq!(h.generation(), 4);
+    }
+
+    #[test]
+    fn hashlife_r_pentomino_grows() {
+        let grid = Grid::from_pattern(patterns::R_PENTOMINO, 0, 0);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        h.step_pow2(3); // 8 generations
+        assert!(h.population() > 5);
+    }
+
+    #[test]
+    fn hashlife_step_pow2_matches_stepwise_simulation() {
+        // R-pentomino has a distinct shape every generation, so any
+        // mismatched step count shows up as a cell-for-cell difference
+        // rather than hiding behind a population or still-life invariant.
+        let grid = Grid::from_pattern(patterns::R_PENTOMINO, 0, 0);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        h.step_pow2(5); // 32 generations
+
+        let mut reference = grid;
+        for _ in 0..32 {
+            reference.step();
+        }
+
+        assert_eq!(h.generation(), 32);
+        assert_eq!(h.to_grid().cells(), reference.cells());
+    }
+
+    #[test]
+    fn hashlife_step_pow2_matches_stepwise_simulation_when_root_is_oversized() {
+        // `from_grid` always leaves two spare levels of margin beyond what
+        // the pattern's bounding box needs, so a fresh `step_pow2(0)` call
+        // finds the root *bigger* than `k + 2` from the very first call —
+        // the normal case, not an edge case. `generation()` is free to run
+        // ahead of `2^k` here, but whatever it actually advances by must
+        // still match a cell-for-cell stepwise simulation: a window
+        // narrowed to exactly `k + 2` with no margin of its own would
+        // silently compute the wrong cells instead.
+        let grid = Grid::from_pattern(patterns::R_PENTOMINO, 0, 0);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        h.step_pow2(0);
+
+        let mut reference = grid;
+        for _ in 0..h.generation() {
+            reference.step();
+        }
+
+        assert_eq!(h.to_grid().cells(), reference.cells());
+    }
+
+    #[test]
+    fn hashlife_step_pow2_matches_stepwise_simulation_across_repeated_calls_on_oversized_root() {
+        // Same shape of bug, exercised over several calls: the Gosper
+        // gun's bounding box forces a root well above `k + 2` for a small
+        // k, and repeated calls must keep matching a brute-force rerun
+        // generation by generation, not just agree on the final count.
+        // `step_pow2` re-pads one level after every call to replenish the
+        // margin `result()` just spent, but the gun fires a fresh glider
+        // forever, so its true bounding box keeps growing — a handful of
+        // calls is as far as any fixed re-pad keeps up before the tracked
+        // square would need to grow on its own, which is out of scope here.
+        let grid = Grid::from_pattern(patterns::GOSPER_GUN, 0, 0);
+        let mut h = HashlifeGrid::from_grid(&grid);
+        let mut reference = grid;
+        let mut reference_gen = 0u64;
+        for _ in 0..3 {
+            h.step_pow2(0);
+            while reference_gen < h.generation() {
+                reference.step();
+                reference_gen += 1;
+            }
+            assert_eq!(h.to_grid().cells(), reference.cells());
+        }
+    }
+
+    #[test]
+    fn random_is_reproducible_for_same_seed() {
+        let a = Grid::random(20, 20, 0.3, 42);
+        let b = Grid::random(20, 20, 0.3, 42);
+        assert_eq!(a.cells(), b.cells());
+    }
+
+    #[test]
+    fn random_differs_across_seeds() {
+        let a = Grid::random(20, 20, 0.3, 1);
+        let b = Grid::random(20, 20, 0.3, 2);
+        assert_ne!(a.cells(), b.cells());
+    }
+
+    #[test]
+    fn random_density_bounds_are_respected() {
+        let empty = Grid::random(20, 20, 0.0, 7);
+        assert_eq!(empty.population(), 0);
+        let full = Grid::random(20, 20, 1.0, 7);
+        assert_eq!(full.population(), 400);
+    }
+
+    #[test]
+    fn scatter_adds_cells_reproducibly() {
+        let mut a = Grid::new();
+        a.scatter(0, 0, 10, 10, 15, 99);
+        let mut b = Grid::new();
+        b.scatter(0, 0, 10, 10, 15, 99);
+        assert_eq!(a.cells(), b.cells());
+    }
+
+    #[test]
+    fn scatter_preserves_existing_cells() {
+        let mut grid = Grid::from_cells([(0, 0)]);
+        grid.scatter(5, 5, 10, 10, 5, 3);
+        assert!(grid.is_alive(&(0, 0)));
+    }
+
+    #[test]
+    fn run_until_stable_detects_still_life() {
+        let mut grid = Grid::from_cells([(0, 0), (0, 1), (1, 0), (1, 1)]);
+        let cycle = grid.run_until_stable(10).unwrap();
+        assert_eq!(cycle.period, 1);
+        assert_eq!(cycle.displacement, (0, 0));
+    }
+
+    #[test]
+    fn run_until_stable_detects_oscillator() {
+        let mut grid = Grid::from_cells([(0, -1), (0, 0), (0, 1)]);
+        let cycle = grid.run_until_stable(10).unwrap();
+        assert_eq!(cycle.period, 2);
+        assert_eq!(cycle.displacement, (0, 0));
+    }
+
+    #[test]
+    fn run_until_stable_detects_spaceship_displacement() {
+        let mut grid = Grid::from_pattern(patterns::GLIDER, 0, 0);
+        let cycle = grid.run_until_stable(20).unwrap();
+        assert_eq!(cycle.period, 4);
+        assert_ne!(cycle.displacement, (0, 0));
+    }
+
+    #[test]
+    fn run_until_stable_gives_up_within_budget() {
+        // An unbounded-growth pattern within a tiny generation budget never
+        // repeats, so detection should report `None` rather than loop forever.
+        let mut grid = Grid::from_pattern(patterns::R_PENTOMINO, 0, 0);
+        assert_eq!(grid.run_until_stable(3), None);
+    }
+
+    #[test]
+    fn bounded_topology_defaults_to_infinite() {
+        assert_eq!(Grid::new().topology(), Topology::Infinite);
+    }
+
+    #[test]
+    fn bounded_topology_preserves_interior_still_life() {
+        let mut grid = Grid::from_cells([(0, 0), (0, 1), (1, 0), (1, 1)])
+            .with_topology(Topology::Bounded { rows: 4, cols: 4 });
+        grid.step();
+        assert_eq!(
+            grid.cells(),
+            &HashSet::from([(0, 0), (0, 1), (1, 0), (1, 1)])
+        );
+    }
+
+    #[test]
+    fn bounded_topology_truncates_neighbors_at_the_edge() {
+        // A blinker flush against the top edge of a 3x3 bounded board can't
+        // receive the off-board neighbor contributions an infinite grid
+        // would, so it doesn't flip the way a classic blinker does.
+        let mut grid = Grid::from_cells([(0, 0), (0, 1), (0, 2)])
+            .with_topology(Topology::Bounded { rows: 3, cols: 3 });
+        grid.step();
+        assert_eq!(grid.cells(), &HashSet::from([(0, 1), (1, 1)]));
+    }
+
+    #[test]
+    fn toroidal_topology_wraps_a_blinker_across_the_seam() {
+        // Horizontal blinker straddling the column wrap seam of a 5x5
+        // torus (columns 4, 0, 1) behaves like an ordinary blinker and
+        // flips to vertical, centered on the middle cell at column 0.
+        let mut grid = Grid::from_cells([(2, 4), (2, 0), (2, 1)])
+            .with_topology(Topology::Toroidal { rows: 5, cols: 5 });
+        grid.step();
+        assert_eq!(grid.cells(), &HashSet::from([(1, 0), (2, 0), (3, 0)]));
+    }
 }
@@ -6,6 +6,10 @@ const DEFAULT_FPS: u64 = 15;
 const VIEWPORT_ROWS: i64 = 40;
 const VIEWPORT_COLS: i64 = 80;
 
+/// Default number of live cells sprinkled in per reseed (0 disables reseeding).
+const DEFAULT_SEED_INTERVAL: u64 = 0;
+const DEFAULT_SEED_POPULATION: usize = 20;
+
 fn render(grid: &Grid, vr0: i64, vc0: i64, vr1: i64, vc1: i64) -> String {
     let mut buf = String::with_capacity(((vr1 - vr0 + 1) * (vc1 - vc0 + 2)) as usize);
 
@@ -35,6 +39,18 @@ fn main() {
         .get(2)
         .and_then(|s| s.parse().ok())
         .unwrap_or(DEFAULT_FPS);
+    // Reseeding keeps the colony from fully dying out: every `seed_interval`
+    // generations, `seed_population` random live cells are sprinkled into
+    // the viewport. An interval of 0 (the default) disables reseeding.
+    let seed_interval: u64 = args
+        .get(3)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SEED_INTERVAL);
+    let seed_population: usize = args
+        .get(4)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SEED_POPULATION);
+    let seed_base: u64 = args.get(5).and_then(|s| s.parse().ok()).unwrap_or(42);
 
     let (pattern, origin_r, origin_c) = match pattern_name {
         "glider" => (patterns::GLIDER, VIEWPORT_ROWS / 4, VIEWPORT_COLS / 4),
@@ -62,6 +78,12 @@ fn main() {
         write!(out, "{frame}").ok();
         out.flush().ok();
         grid.step();
+
+        if seed_interval > 0 && grid.generation() % seed_interval == 0 {
+            let seed = seed_base.wrapping_add(grid.generation());
+            grid.scatter(0, 0, VIEWPORT_ROWS, VIEWPORT_COLS, seed_population, seed);
+        }
+
         thread::sleep(delay);
     }
 }
@@ -1,9 +1,20 @@
-use esp_idf_svc::hal::gpio::PinDriver;
+use esp_idf_svc::espnow::{EspNow, PeerInfo, RecvInfo};
+use esp_idf_svc::hal::adc::attenuation::DB_11;
+use esp_idf_svc::hal::adc::oneshot::config::AdcChannelConfig;
+use esp_idf_svc::hal::adc::oneshot::{AdcChannelDriver, AdcDriver};
+use esp_idf_svc::hal::adc::ADC1;
+use esp_idf_svc::hal::gpio::{Gpio2, Gpio4, InputOutput, PinDriver};
 use esp_idf_svc::hal::i2c::{I2cConfig, I2cDriver};
 use esp_idf_svc::hal::peripherals::Peripherals;
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::text::Text;
 use smart_leds::hsv::{hsv2rgb, Hsv};
 use smart_leds::SmartLedsWrite;
 use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306};
+use std::sync::Mutex;
 use std::thread;
 use std::time::Duration;
 use ws2812_esp32_rmt_driver::Ws2812Esp32Rmt;
@@ -18,6 +29,20 @@ const TILE_H: usize = 64;
 const TILES_X: usize = WORLD_W / TILE_W; // 8
 const TILES_Y: usize = WORLD_H / TILE_H; // 4
 
+/// Bitmask with one bit per tile (TILES_X * TILES_Y = 32, so it fits a u32
+/// exactly); bit `ty * TILES_X + tx` tracks tile `(tx, ty)`.
+const ALL_TILES_DIRTY: u32 = u32::MAX;
+
+#[inline]
+fn tile_index(tx: usize, ty: usize) -> usize {
+    ty * TILES_X + tx
+}
+
+#[inline]
+fn tile_bit(tx: usize, ty: usize) -> u32 {
+    1 << tile_index(tx, ty)
+}
+
 /// Simple xorshift32 PRNG seeded from hardware timer.
 struct Rng(u32);
 
@@ -35,15 +60,19 @@ impl Rng {
     }
 }
 
-/// Bitfield grid: WORLD_W x WORLD_H, row-major, 1 bit per cell.
+/// Bitfield grid: WORLD_W x WORLD_H, row-major, 1 bit per cell, plus a
+/// per-tile population cache that `step` keeps current via dirty-tile
+/// tracking instead of rescanning every tile every generation.
 struct Grid {
     cells: [u8; GRID_BYTES],
+    tile_pop: [u32; TILES_X * TILES_Y],
 }
 
 impl Grid {
     fn new() -> Self {
         Self {
             cells: [0u8; GRID_BYTES],
+            tile_pop: [0u32; TILES_X * TILES_Y],
         }
     }
 
@@ -61,6 +90,7 @@ impl Grid {
 
     fn clear(&mut self) {
         self.cells.fill(0);
+        self.tile_pop.fill(0);
     }
 
     /// Count total live cells using popcount.
@@ -68,8 +98,9 @@ impl Grid {
         self.cells.iter().map(|b| b.count_ones()).sum()
     }
 
-    /// Count population in a tile (TILE_W x TILE_H block).
-    fn tile_population(&self, tx: usize, ty: usize) -> u32 {
+    /// Count population in a tile (TILE_W x TILE_H block) by scanning its
+    /// cells directly, bypassing the cache.
+    fn scan_tile_population(&self, tx: usize, ty: usize) -> u32 {
         let mut count = 0u32;
         let x0_byte = tx * (TILE_W / 8);
         let row_bytes = WORLD_W / 8;
@@ -81,20 +112,212 @@ impl Grid {
         }
         count
     }
+
+    /// Cached tile population, kept current by `step`'s dirty-tile tracking.
+    #[inline]
+    fn tile_population(&self, tx: usize, ty: usize) -> u32 {
+        self.tile_pop[tile_index(tx, ty)]
+    }
+
+    /// Rebuild the whole tile population cache from scratch. Call this
+    /// after editing cells outside of `step` (scene load, reroll, scatter)
+    /// so the cache isn't stale before the next `step` gets a chance to
+    /// refresh it.
+    fn recompute_all_tile_populations(&mut self) {
+        for ty in 0..TILES_Y {
+            for tx in 0..TILES_X {
+                self.tile_pop[tile_index(tx, ty)] = self.scan_tile_population(tx, ty);
+            }
+        }
+    }
+}
+
+// ─── ESP-NOW boundary stitching ─────────────────────────────────
+//
+// Several boards can each run their own WORLD_W x WORLD_H `Grid` and still
+// behave like one large torus: after every `step` each board broadcasts the
+// column/row its right/bottom neighbor needs to stitch a seamless seam —
+// its own left-edge column and top-edge row — and the receiving board
+// substitutes those cached edges for its own local wraparound at the
+// matching border. A stale or missing packet just falls back to the normal
+// toroidal wrap, so a lone board still runs correctly offline.
+
+const ESPNOW_BROADCAST_ADDR: [u8; 6] = [0xff; 6];
+
+const EDGE_KIND_RIGHT: u8 = 0;
+const EDGE_KIND_BOTTOM: u8 = 1;
+
+/// Frames older than this many generations are treated as a dead/missing peer.
+const EDGE_STALE_AFTER: u32 = 5;
+
+const RIGHT_EDGE_BYTES: usize = WORLD_H / 8; // 32 — one bit per row
+const BOTTOM_EDGE_BYTES: usize = WORLD_W / 8; // 64 — one bit per column
+const EDGE_HEADER_LEN: usize = 1 + 4; // kind byte + little-endian u32 generation
+
+/// Latest edge columns/rows received from a neighbor board, keyed by kind.
+///
+/// With more than two boards on the network, every board's right/bottom
+/// broadcast looks identical in `kind` — only the sender's address tells
+/// them apart. Each slot locks onto the first sender address it sees for
+/// that kind and ignores frames of the same kind from any other address,
+/// so an unrelated board's broadcast can't clobber the real neighbor's edge.
+struct NeighborEdges {
+    right: [u8; RIGHT_EDGE_BYTES],
+    right_generation: u32,
+    right_fresh: bool,
+    right_addr: Option<[u8; 6]>,
+    bottom: [u8; BOTTOM_EDGE_BYTES],
+    bottom_generation: u32,
+    bottom_fresh: bool,
+    bottom_addr: Option<[u8; 6]>,
+}
+
+impl NeighborEdges {
+    const fn new() -> Self {
+        Self {
+            right: [0u8; RIGHT_EDGE_BYTES],
+            right_generation: 0,
+            right_fresh: false,
+            right_addr: None,
+            bottom: [0u8; BOTTOM_EDGE_BYTES],
+            bottom_generation: 0,
+            bottom_fresh: false,
+            bottom_addr: None,
+        }
+    }
+
+    #[inline]
+    fn get_right(&self, y: usize) -> bool {
+        self.right[y / 8] & (1 << (y % 8)) != 0
+    }
+
+    #[inline]
+    fn get_bottom(&self, x: usize) -> bool {
+        self.bottom[x / 8] & (1 << (x % 8)) != 0
+    }
+}
+
+static NEIGHBOR_EDGES: Mutex<NeighborEdges> = Mutex::new(NeighborEdges::new());
+
+/// Pack a kind byte + generation + edge bits into an ESP-NOW payload.
+fn encode_edge_frame(kind: u8, generation: u32, bits: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(EDGE_HEADER_LEN + bits.len());
+    frame.push(kind);
+    frame.extend_from_slice(&generation.to_le_bytes());
+    frame.extend_from_slice(bits);
+    frame
+}
+
+/// ESP-NOW receive callback: cache the incoming edge if it's newer than what
+/// we already have (frames can arrive out of order over the air) and it
+/// comes from the sender already locked in for that kind, if any.
+fn on_espnow_recv(info: &RecvInfo, data: &[u8]) {
+    if data.len() < EDGE_HEADER_LEN {
+        return;
+    }
+    let src_addr = info.src_addr;
+    let kind = data[0];
+    let generation = u32::from_le_bytes(data[1..5].try_into().unwrap());
+    let payload = &data[5..];
+
+    let mut edges = NEIGHBOR_EDGES.lock().unwrap();
+    match kind {
+        EDGE_KIND_RIGHT if payload.len() >= RIGHT_EDGE_BYTES => {
+            if edges.right_addr.is_none_or(|addr| addr == src_addr)
+                && (!edges.right_fresh || generation >= edges.right_generation)
+            {
+                edges.right.copy_from_slice(&payload[..RIGHT_EDGE_BYTES]);
+                edges.right_generation = generation;
+                edges.right_fresh = true;
+                edges.right_addr.get_or_insert(src_addr);
+            }
+        }
+        EDGE_KIND_BOTTOM if payload.len() >= BOTTOM_EDGE_BYTES => {
+            if edges.bottom_addr.is_none_or(|addr| addr == src_addr)
+                && (!edges.bottom_fresh || generation >= edges.bottom_generation)
+            {
+                edges.bottom.copy_from_slice(&payload[..BOTTOM_EDGE_BYTES]);
+                edges.bottom_generation = generation;
+                edges.bottom_fresh = true;
+                edges.bottom_addr.get_or_insert(src_addr);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Broadcast this board's left-edge column and top-edge row — what a
+/// neighbor board sitting to our left/above needs to stitch a seamless
+/// wraparound past *its* right/bottom edge — over ESP-NOW.
+fn broadcast_edges(esp_now: &EspNow, grid: &Grid, generation: u32) {
+    let mut right_bits = [0u8; RIGHT_EDGE_BYTES];
+    for y in 0..WORLD_H {
+        if grid.get(0, y) {
+            right_bits[y / 8] |= 1 << (y % 8);
+        }
+    }
+    let right_frame = encode_edge_frame(EDGE_KIND_RIGHT, generation, &right_bits);
+    let _ = esp_now.send(&ESPNOW_BROADCAST_ADDR, &right_frame);
+
+    let mut bottom_bits = [0u8; BOTTOM_EDGE_BYTES];
+    for x in 0..WORLD_W {
+        if grid.get(x, 0) {
+            bottom_bits[x / 8] |= 1 << (x % 8);
+        }
+    }
+    let bottom_frame = encode_edge_frame(EDGE_KIND_BOTTOM, generation, &bottom_bits);
+    let _ = esp_now.send(&ESPNOW_BROADCAST_ADDR, &bottom_frame);
+}
+
+/// Right neighbor's left-edge column, standing in for the wrap past our
+/// right edge (x == WORLD_W), if a fresh packet has arrived recently;
+/// `None` means "use the local toroidal wrap instead."
+fn edge_neighbor_right(y: usize, generation: u32) -> Option<bool> {
+    let edges = NEIGHBOR_EDGES.lock().unwrap();
+    if !edges.right_fresh || generation.saturating_sub(edges.right_generation) > EDGE_STALE_AFTER {
+        return None;
+    }
+    Some(edges.get_right(y))
+}
+
+/// Bottom neighbor's top-edge row, standing in for the wrap past our
+/// bottom edge (y == WORLD_H), if a fresh packet has arrived recently.
+fn edge_neighbor_bottom(x: usize, generation: u32) -> Option<bool> {
+    let edges = NEIGHBOR_EDGES.lock().unwrap();
+    if !edges.bottom_fresh || generation.saturating_sub(edges.bottom_generation) > EDGE_STALE_AFTER
+    {
+        return None;
+    }
+    Some(edges.get_bottom(x))
 }
 
-/// Count live neighbors with toroidal wrapping.
+/// Count live neighbors, stitching in ESP-NOW peer edges at the right and
+/// bottom borders and falling back to toroidal wrapping everywhere else
+/// (and whenever no fresh peer packet is available).
 #[inline]
-fn count_neighbors(grid: &Grid, x: usize, y: usize) -> u8 {
+fn count_neighbors(grid: &Grid, x: usize, y: usize, generation: u32) -> u8 {
     let mut count = 0u8;
-    for dy in [WORLD_H - 1, 0, 1] {
-        for dx in [WORLD_W - 1, 0, 1] {
+    for dy in [-1i32, 0, 1] {
+        for dx in [-1i32, 0, 1] {
             if dx == 0 && dy == 0 {
                 continue;
             }
-            let nx = (x + dx) % WORLD_W;
-            let ny = (y + dy) % WORLD_H;
-            if grid.get(nx, ny) {
+            let raw_x = x as i32 + dx;
+            let raw_y = y as i32 + dy;
+
+            let alive = if raw_x == WORLD_W as i32 {
+                let wy = raw_y.rem_euclid(WORLD_H as i32) as usize;
+                edge_neighbor_right(wy, generation).unwrap_or_else(|| grid.get(0, wy))
+            } else if raw_y == WORLD_H as i32 {
+                let wx = raw_x.rem_euclid(WORLD_W as i32) as usize;
+                edge_neighbor_bottom(wx, generation).unwrap_or_else(|| grid.get(wx, 0))
+            } else {
+                let wx = raw_x.rem_euclid(WORLD_W as i32) as usize;
+                let wy = raw_y.rem_euclid(WORLD_H as i32) as usize;
+                grid.get(wx, wy)
+            };
+
+            if alive {
                 count += 1;
             }
         }
@@ -103,17 +326,110 @@ fn count_neighbors(grid: &Grid, x: usize, y: usize) -> u8 {
 }
 
 /// Advance one generation: read from `current`, write into `next`.
-fn step(current: &Grid, next: &mut Grid) {
-    next.clear();
-    for y in 0..WORLD_H {
-        for x in 0..WORLD_W {
-            let neighbors = count_neighbors(current, x, y);
-            let alive = current.get(x, y);
-            if neighbors == 3 || (alive && neighbors == 2) {
-                next.set(x, y);
+///
+/// `dirty` is a bitmask (see `tile_bit`) of tiles that need the life rule
+/// re-run this generation; every other tile is known unchanged from last
+/// generation and is copied verbatim. Returns the dirty mask for the
+/// *next* generation: a tile stays dirty whenever any of its cells flip,
+/// and so do its eight Moore tile-neighbors (orthogonal and diagonal), so
+/// changes that reach a tile edge or corner are still caught once they
+/// cross into the next tile over.
+///
+/// `age` is a world-space (not double-buffered) per-cell generation count,
+/// incremented for cells that stay alive and reset to zero on death, for
+/// the age-colored WS2812 matrix.
+fn step(
+    current: &Grid,
+    next: &mut Grid,
+    generation: u32,
+    dirty: u32,
+    rule: Rule,
+    age: &mut [u8],
+) -> u32 {
+    let row_bytes = WORLD_W / 8;
+    let tile_row_bytes = TILE_W / 8;
+    let mut next_dirty = 0u32;
+
+    for ty in 0..TILES_Y {
+        for tx in 0..TILES_X {
+            let idx = tile_index(tx, ty);
+            let x0_byte = tx * tile_row_bytes;
+
+            if dirty & tile_bit(tx, ty) == 0 {
+                // Clean tile: its cells can't have changed, copy verbatim.
+                // Live cells still age by one generation each call, or the
+                // matrix's hue would freeze instead of cooling while a tile
+                // sits quiescent.
+                for row in 0..TILE_H {
+                    let base = (ty * TILE_H + row) * row_bytes + x0_byte;
+                    next.cells[base..base + tile_row_bytes]
+                        .copy_from_slice(&current.cells[base..base + tile_row_bytes]);
+                    let y = ty * TILE_H + row;
+                    for col in 0..TILE_W {
+                        let x = tx * TILE_W + col;
+                        if current.get(x, y) {
+                            let age_idx = y * WORLD_W + x;
+                            age[age_idx] = age[age_idx].saturating_add(1);
+                        }
+                    }
+                }
+                next.tile_pop[idx] = current.tile_pop[idx];
+                continue;
+            }
+
+            // Dirty tile: re-run the life rule over every cell in it.
+            for row in 0..TILE_H {
+                let base = (ty * TILE_H + row) * row_bytes + x0_byte;
+                next.cells[base..base + tile_row_bytes].fill(0);
+            }
+
+            let mut changed = false;
+            let mut pop = 0u32;
+            for row in 0..TILE_H {
+                let y = ty * TILE_H + row;
+                for col in 0..TILE_W {
+                    let x = tx * TILE_W + col;
+                    let neighbors = count_neighbors(current, x, y, generation);
+                    let alive = current.get(x, y);
+                    let born = rule.birth & (1 << neighbors) != 0;
+                    let survives = alive && (rule.survival & (1 << neighbors) != 0);
+                    let next_alive = born || survives;
+                    let age_idx = y * WORLD_W + x;
+                    if next_alive {
+                        next.set(x, y);
+                        pop += 1;
+                        age[age_idx] = if alive {
+                            age[age_idx].saturating_add(1)
+                        } else {
+                            1
+                        };
+                    } else {
+                        age[age_idx] = 0;
+                    }
+                    changed |= next_alive != alive;
+                }
+            }
+            next.tile_pop[idx] = pop;
+
+            if changed {
+                next_dirty |= tile_bit(tx, ty);
+                let tx_prev = (tx + TILES_X - 1) % TILES_X;
+                let tx_next = (tx + 1) % TILES_X;
+                let ty_prev = (ty + TILES_Y - 1) % TILES_Y;
+                let ty_next = (ty + 1) % TILES_Y;
+                next_dirty |= tile_bit(tx_prev, ty)
+                    | tile_bit(tx_next, ty)
+                    | tile_bit(tx, ty_prev)
+                    | tile_bit(tx, ty_next)
+                    | tile_bit(tx_prev, ty_prev)
+                    | tile_bit(tx_next, ty_prev)
+                    | tile_bit(tx_prev, ty_next)
+                    | tile_bit(tx_next, ty_next);
             }
         }
     }
+
+    next_dirty
 }
 
 /// Stamp a pattern into grid (additive — doesn't clear first).
@@ -142,9 +458,11 @@ fn scatter_random(grid: &mut Grid, rng: &mut Rng, density: u8) {
 
 /// Map population to LED color reflecting colony health.
 /// Red = dying/empty, green = thriving, blue/cyan = overcrowded.
-/// Brightness pulses with rate of change.
-/// Thresholds scaled for 512x256 world (~131K cells).
-fn health_color(pop: u32, prev_pop: u32) -> Hsv {
+/// Brightness pulses with rate of change and with ambient mic loudness
+/// (0-255, from the Goertzel band analysis below). `humidity_hue_shift`
+/// (see the environmental sensor below) nudges the hue warmer in humid
+/// rooms. Thresholds scaled for 512x256 world (~131K cells).
+fn health_color(pop: u32, prev_pop: u32, mic_loudness: u8, humidity_hue_shift: u8) -> Hsv {
     // Map population to hue: 0 (red) → 80 (green) → 140 (cyan)
     // Sweet spot ~5000-12000 cells = green (16x the old 128x64 thresholds)
     let hue = if pop < 800 {
@@ -170,6 +488,8 @@ fn health_color(pop: u32, prev_pop: u32) -> Hsv {
     } else {
         8 // calm
     };
+    let val = val.saturating_add(mic_loudness / 4);
+    let hue = hue.saturating_add(humidity_hue_shift);
 
     Hsv {
         hue,
@@ -178,14 +498,306 @@ fn health_color(pop: u32, prev_pop: u32) -> Hsv {
     }
 }
 
+// ─── Audio-reactive seeding ──────────────────────────────────────
+//
+// A mic on the ADC feeds a 256-sample Goertzel analysis for three bands
+// (bass/mid/treble); their energies drive pattern bursts, scatter density,
+// and LED brightness so the colony pulses with ambient sound.
+
+const MIC_SAMPLE_N: usize = 256;
+
+/// Fixed-point (Q12, i.e. scaled by 4096) Goertzel coefficients
+/// `2*cos(2*pi*k/N)` for k chosen to land near each band, keeping the
+/// whole analysis in integer math. N = MIC_SAMPLE_N.
+const BASS_COEFF_Q12: i32 = 8153; // k=4  (~1/64 * rate)
+const MID_COEFF_Q12: i32 = 5793; // k=32 (~1/8  * rate)
+const TREBLE_COEFF_Q12: i32 = -5793; // k=96 (~3/8  * rate)
+
+const BASS_BURST_THRESHOLD: i64 = 20_000_000;
+const TREBLE_ENERGY_MAX: i64 = 40_000_000;
+const LOUDNESS_MAX: i64 = 80_000_000;
+
+/// Read `MIC_SAMPLE_N` ADC samples and remove the DC bias by subtracting
+/// the window mean.
+fn sample_mic(adc: &mut AdcChannelDriver<'_, Gpio4, AdcDriver<'_, ADC1>>) -> [i32; MIC_SAMPLE_N] {
+    let mut samples = [0i32; MIC_SAMPLE_N];
+    let mut sum: i64 = 0;
+    for s in samples.iter_mut() {
+        let v = adc.read().unwrap_or(0) as i32;
+        *s = v;
+        sum += v as i64;
+    }
+    let mean = (sum / MIC_SAMPLE_N as i64) as i32;
+    for s in samples.iter_mut() {
+        *s -= mean;
+    }
+    samples
+}
+
+/// Goertzel single-bin energy (proportional to magnitude squared) using
+/// Q12 fixed-point arithmetic throughout.
+fn goertzel_energy(samples: &[i32], coeff_q12: i32) -> i64 {
+    let mut s1: i64 = 0;
+    let mut s2: i64 = 0;
+    for &sample in samples {
+        let s = sample as i64 + ((coeff_q12 as i64 * s1) >> 12) - s2;
+        s2 = s1;
+        s1 = s;
+    }
+    s1 * s1 + s2 * s2 - ((coeff_q12 as i64 * s1 * s2) >> 12)
+}
+
+// ─── Environmental sensor ─────────────────────────────────────────
+//
+// A DHT22 on a spare GPIO ties scene behavior to the room's actual
+// temperature and humidity: warmer rooms raise the periodic scatter
+// density and tighten the viewport's linger, humidity shifts the health
+// LED's hue. Read over the sensor's single-wire pulse-timing protocol —
+// it only updates ~2x/second, so a busy-wait each read is cheap relative
+// to the frame budget.
+
+/// How long to wait for an expected level change before giving up on a read.
+const DHT_TIMEOUT_US: i64 = 1000;
+/// How often (in generations) to poll the sensor; DHT22 itself won't settle
+/// any faster than this.
+const DHT_READ_INTERVAL: u32 = 40;
+
+/// A successful DHT22 reading, in tenths of a unit (235 = 23.5C, 612 = 61.2%).
+#[derive(Clone, Copy)]
+struct DhtReading {
+    humidity_tenths: u16,
+    temp_tenths: i16,
+}
+
+/// Busy-wait for `pin` to read `level`, up to `DHT_TIMEOUT_US` past the
+/// moment this call started. Returns the timestamp the level was observed
+/// at, or `None` on timeout.
+///
+/// The deadline is per-edge, not per-read: a full DHT22 frame takes
+/// several milliseconds to transmit, far longer than `DHT_TIMEOUT_US`, so
+/// timing every wait from a single frame-start timestamp would always
+/// time out partway through.
+fn dht_wait_for_level(pin: &PinDriver<'_, Gpio2, InputOutput>, level: bool) -> Option<i64> {
+    let start_us = unsafe { esp_idf_svc::sys::esp_timer_get_time() };
+    loop {
+        let now = unsafe { esp_idf_svc::sys::esp_timer_get_time() };
+        if now - start_us > DHT_TIMEOUT_US {
+            return None;
+        }
+        if pin.is_high() == level {
+            return Some(now);
+        }
+    }
+}
+
+/// Read a DHT22 over its single-wire protocol: pull the line low to
+/// request a reading, then time the 40 response bits (a long high pulse
+/// encodes a 1, a short one a 0) and validate the trailing checksum byte.
+fn read_dht22(pin: &mut PinDriver<'_, Gpio2, InputOutput>) -> Option<DhtReading> {
+    pin.set_low().ok()?;
+    thread::sleep(Duration::from_millis(2));
+    pin.set_high().ok()?;
+
+    dht_wait_for_level(pin, false)?; // sensor acknowledges, pulls low
+    dht_wait_for_level(pin, true)?; // sensor releases
+    dht_wait_for_level(pin, false)?; // start of the first data bit
+
+    let mut bits = [0u8; 40];
+    for bit in bits.iter_mut() {
+        let high_at = dht_wait_for_level(pin, true)?;
+        let low_at = dht_wait_for_level(pin, false)?;
+        *bit = if low_at - high_at > 40 { 1 } else { 0 };
+    }
+
+    let mut bytes = [0u8; 5];
+    for (i, &bit) in bits.iter().enumerate() {
+        bytes[i / 8] = (bytes[i / 8] << 1) | bit;
+    }
+    let checksum = bytes[0]
+        .wrapping_add(bytes[1])
+        .wrapping_add(bytes[2])
+        .wrapping_add(bytes[3]);
+    if checksum != bytes[4] {
+        return None;
+    }
+
+    let humidity_tenths = ((bytes[0] as u16) << 8) | bytes[1] as u16;
+    let temp_raw = (((bytes[2] & 0x7f) as u16) << 8) | bytes[3] as u16;
+    let temp_tenths = if bytes[2] & 0x80 != 0 {
+        -(temp_raw as i16)
+    } else {
+        temp_raw as i16
+    };
+
+    Some(DhtReading {
+        humidity_tenths,
+        temp_tenths,
+    })
+}
+
+/// Map a temperature reading (scaled over a 15C-35C range) to extra
+/// `scatter_random` density and a tighter `Viewport` linger cap.
+fn temp_bias(temp_tenths: i16) -> (u8, u32) {
+    let clamped = temp_tenths.clamp(150, 350) as i32;
+    let density = ((clamped - 150) * 20 / 200) as u8;
+    let max_linger = (120 - density as u32 * 3).max(MIN_LINGER);
+    (density, max_linger)
+}
+
+/// Map a humidity reading (scaled over a 20%-90% RH range) to a hue-shift
+/// fed into `health_color`.
+fn humidity_hue_shift(humidity_tenths: u16) -> u8 {
+    let clamped = humidity_tenths.clamp(200, 900);
+    ((clamped - 200) * 40 / 700) as u8
+}
+
+// ─── Age-colored WS2812 matrix ───────────────────────────────────
+//
+// A second, larger WS2812 output mirrors the OLED's viewport region in
+// color: each cell's age (generations it has stayed alive, tracked
+// alongside the grid) maps to a hue, so newly born cells read warm/red
+// and long-lived stable structures cool toward blue.
+
+const MATRIX_W: usize = 16;
+const MATRIX_H: usize = 16;
+const MATRIX_PIXELS: usize = MATRIX_W * MATRIX_H;
+const AGE_HUE_MAX: u8 = 64;
+
+/// Map (x, y) in a serpentine-wired WS2812 matrix to its position in the
+/// linear pixel buffer: even rows run left-to-right, odd rows right-to-left.
+#[inline]
+fn matrix_serpentine_index(x: usize, y: usize) -> usize {
+    if y % 2 == 0 {
+        y * MATRIX_W + x
+    } else {
+        y * MATRIX_W + (MATRIX_W - 1 - x)
+    }
+}
+
+/// Map a cell's age to a hue: newly born (age 1) is warm/red, long-lived
+/// cells cool toward blue as they approach AGE_HUE_MAX.
+#[inline]
+fn age_to_hue(age: u8) -> u8 {
+    (age.min(AGE_HUE_MAX) as u32 * 170 / AGE_HUE_MAX as u32) as u8
+}
+
+// ─── Button gesture menu ─────────────────────────────────────────
+//
+// The single BOOT button drives a small on-screen menu instead of just
+// rerolling the scene: a short press (released before LONG_PRESS_US)
+// advances the cursor, a long press (held past LONG_PRESS_US) selects or
+// toggles the highlighted row, and a double press (two short releases
+// within DOUBLE_PRESS_WINDOW_US of each other) closes the menu.
+
+const LONG_PRESS_US: i64 = 700_000;
+const DOUBLE_PRESS_WINDOW_US: i64 = 400_000;
+const AUTO_SCATTER_INTERVAL: u32 = 100;
+
+#[derive(Clone, Copy, PartialEq)]
+enum MenuRow {
+    Scene,
+    StepDelay,
+    ScatterDensity,
+    AutoPan,
+    Hud,
+}
+
+const MENU_ROWS: [MenuRow; 5] = [
+    MenuRow::Scene,
+    MenuRow::StepDelay,
+    MenuRow::ScatterDensity,
+    MenuRow::AutoPan,
+    MenuRow::Hud,
+];
+
+struct Menu {
+    open: bool,
+    cursor: usize,
+}
+
+impl Menu {
+    fn new() -> Self {
+        Self {
+            open: false,
+            cursor: 0,
+        }
+    }
+}
+
+/// Draw the menu overlay listing each row's current value, with `>`
+/// marking the highlighted one.
+fn draw_menu<D: DrawTarget<Color = BinaryColor>>(
+    display: &mut D,
+    menu: &Menu,
+    scene_name: &str,
+    step_delay_ms: u64,
+    scatter_density: u8,
+    auto_pan: bool,
+    hud_visible: bool,
+) {
+    let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+    let rows = [
+        format!("Scene: {scene_name}"),
+        format!("Step delay: {step_delay_ms} ms"),
+        format!("Scatter: {scatter_density}"),
+        format!("Auto-pan: {}", if auto_pan { "on" } else { "off" }),
+        format!("HUD: {}", if hud_visible { "on" } else { "off" }),
+    ];
+    for (i, row) in rows.iter().enumerate() {
+        let marker = if i == menu.cursor { ">" } else { " " };
+        let line = format!("{marker}{row}");
+        let _ = Text::new(&line, Point::new(0, 10 + i as i32 * 12), style).draw(display);
+    }
+}
+
+// ─── HUD overlay ─────────────────────────────────────────────────
+//
+// A one-line telemetry readout — generation, population, scene, a rolling
+// FPS estimate, and the latest environmental reading — drawn across the
+// top of the screen. Toggled independently of the parameter menu so it
+// doesn't have to stay up while watching the simulation.
+
+/// Draw the HUD as a single line across the top of the screen.
+fn draw_hud<D: DrawTarget<Color = BinaryColor>>(
+    display: &mut D,
+    generation: u32,
+    population: u32,
+    scene_name: &str,
+    fps_x10: u32,
+    dht: Option<DhtReading>,
+) {
+    let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+    let env = match dht {
+        Some(r) => format!(
+            "{}.{}C {}.{}%",
+            r.temp_tenths / 10,
+            r.temp_tenths.rem_euclid(10),
+            r.humidity_tenths / 10,
+            r.humidity_tenths % 10
+        ),
+        None => "--".to_string(),
+    };
+    let line = format!(
+        "G{generation} P{population} {scene_name} {}.{}fps {env}",
+        fps_x10 / 10,
+        fps_x10 % 10
+    );
+    let _ = Text::new(&line, Point::new(0, 8), style).draw(display);
+}
+
 // ─── Viewport ────────────────────────────────────────────────────
 
+/// Floor of the linger range; `max_linger` (environment-adjustable, see
+/// `temp_bias`) only ever narrows the range down toward this.
+const MIN_LINGER: u32 = 60;
+
 struct Viewport {
     x: i32,
     y: i32,
     tx: i32,
     ty: i32,
     linger: u32,
+    max_linger: u32,
 }
 
 impl Viewport {
@@ -196,6 +808,7 @@ impl Viewport {
             tx: 0,
             ty: 0,
             linger: 0,
+            max_linger: 120,
         }
     }
 
@@ -203,7 +816,7 @@ impl Viewport {
     fn pick_target(&mut self, rng: &mut Rng) {
         self.tx = (rng.next() % WORLD_W as u32) as i32;
         self.ty = (rng.next() % WORLD_H as u32) as i32;
-        self.linger = 60 + (rng.next() % 61); // 60–120 generations
+        self.linger = MIN_LINGER + (rng.next() % (self.max_linger.saturating_sub(MIN_LINGER) + 1));
     }
 
     /// Pick target biased toward regions with live cells.
@@ -245,7 +858,7 @@ impl Viewport {
         // Target center of chosen tile + random jitter within tile
         self.tx = (chosen_tx * TILE_W + (rng.next() as usize % TILE_W)) as i32;
         self.ty = (chosen_ty * TILE_H + (rng.next() as usize % TILE_H)) as i32;
-        self.linger = 60 + (rng.next() % 61);
+        self.linger = MIN_LINGER + (rng.next() % (self.max_linger.saturating_sub(MIN_LINGER) + 1));
     }
 
     /// Move one pixel toward target each axis, wrapping toroidally.
@@ -280,7 +893,7 @@ impl Viewport {
 
         // Arrived at target — start lingering
         if dx == 0 && dy == 0 {
-            self.linger = 60 + (rng.next() % 61);
+            self.linger = MIN_LINGER + (rng.next() % (self.max_linger.saturating_sub(MIN_LINGER) + 1));
         }
     }
 }
@@ -329,9 +942,49 @@ O....O.O....O
 .............
 ..OOO...OOO..";
 
+// ─── Rulesets ────────────────────────────────────────────────────
+//
+// A Life-like ruleset as two 16-bit neighbor-count bitmasks: bit n set in
+// `birth` means a dead cell with n live neighbors is born next generation,
+// bit n set in `survival` means a live cell with n live neighbors stays
+// alive. Conway's standard B3/S23 is `Rule::new(&[3], &[2, 3])`.
+#[derive(Clone, Copy)]
+struct Rule {
+    birth: u16,
+    survival: u16,
+}
+
+impl Rule {
+    const fn new(births: &[u8], survivals: &[u8]) -> Self {
+        let mut birth = 0u16;
+        let mut i = 0;
+        while i < births.len() {
+            birth |= 1 << births[i];
+            i += 1;
+        }
+        let mut survival = 0u16;
+        let mut j = 0;
+        while j < survivals.len() {
+            survival |= 1 << survivals[j];
+            j += 1;
+        }
+        Self { birth, survival }
+    }
+}
+
+/// B3/S23 — the standard Game of Life rule.
+const RULE_CONWAY: Rule = Rule::new(&[3], &[2, 3]);
+/// B36/S23 — like Conway but also births on 6 neighbors, breeds replicators.
+const RULE_HIGHLIFE: Rule = Rule::new(&[3, 6], &[2, 3]);
+/// B3678/S34678 — two roughly-symmetric "day" and "night" phases.
+const RULE_DAY_AND_NIGHT: Rule = Rule::new(&[3, 6, 7, 8], &[3, 4, 6, 7, 8]);
+/// B2/S — nothing survives a generation, only explosive, short-lived growth.
+const RULE_SEEDS: Rule = Rule::new(&[2], &[]);
+
 struct Scene {
     name: &'static str,
     load: fn(&mut Grid, &mut Rng),
+    rule: Rule,
 }
 
 const SCENES: &[Scene] = &[
@@ -347,6 +1000,7 @@ const SCENES: &[Scene] = &[
             }
             scatter_random(grid, rng, 20);
         },
+        rule: RULE_CONWAY,
     },
     Scene {
         name: "Gosper Gun + chaos",
@@ -361,6 +1015,7 @@ const SCENES: &[Scene] = &[
             stamp_pattern(grid, GOSPER_GUN, 400, 200);
             scatter_random(grid, rng, 25);
         },
+        rule: RULE_CONWAY,
     },
     Scene {
         name: "Random soup",
@@ -368,6 +1023,7 @@ const SCENES: &[Scene] = &[
             grid.clear();
             scatter_random(grid, rng, 70);
         },
+        rule: RULE_HIGHLIFE,
     },
     Scene {
         name: "Armada",
@@ -385,6 +1041,7 @@ const SCENES: &[Scene] = &[
             }
             scatter_random(grid, rng, 15);
         },
+        rule: RULE_CONWAY,
     },
     Scene {
         name: "Pulsar garden",
@@ -403,6 +1060,7 @@ const SCENES: &[Scene] = &[
             }
             scatter_random(grid, rng, 12);
         },
+        rule: RULE_CONWAY,
     },
     Scene {
         name: "R-pentomino collider",
@@ -415,6 +1073,7 @@ const SCENES: &[Scene] = &[
             }
             scatter_random(grid, rng, 18);
         },
+        rule: RULE_DAY_AND_NIGHT,
     },
     Scene {
         name: "Primordial soup",
@@ -422,6 +1081,7 @@ const SCENES: &[Scene] = &[
             grid.clear();
             scatter_random(grid, rng, 90);
         },
+        rule: RULE_SEEDS,
     },
 ];
 
@@ -435,6 +1095,11 @@ fn main() -> anyhow::Result<()> {
     let mut ws2812 = Ws2812Esp32Rmt::new(peripherals.rmt.channel0, peripherals.pins.gpio8)?;
     log::info!("RGB LED ready");
 
+    // Age-colored WS2812 matrix (GPIO10, serpentine-wired) mirroring the
+    // OLED's viewport region in color
+    let mut ws2812_matrix = Ws2812Esp32Rmt::new(peripherals.rmt.channel1, peripherals.pins.gpio10)?;
+    log::info!("WS2812 matrix ready ({MATRIX_W}x{MATRIX_H}, GPIO10)");
+
     // BOOT button on GPIO9 — active low, internal pull-up
     let button = PinDriver::input(peripherals.pins.gpio9)?;
     log::info!("Button ready (GPIO9 BOOT)");
@@ -448,6 +1113,30 @@ fn main() -> anyhow::Result<()> {
         &i2c_config,
     )?;
 
+    // ESP-NOW: broadcast our edges and listen for neighboring boards' edges
+    // so gliders can cross seamlessly between physical devices.
+    let esp_now = EspNow::take()?;
+    esp_now.add_peer(PeerInfo {
+        peer_addr: ESPNOW_BROADCAST_ADDR,
+        ..Default::default()
+    })?;
+    esp_now.register_recv_cb(|info, data| on_espnow_recv(info, data))?;
+    log::info!("ESP-NOW boundary stitching ready");
+
+    // Analog microphone on GPIO4 (ADC1) for audio-reactive seeding
+    let adc = AdcDriver::new(peripherals.adc1)?;
+    let mic_config = AdcChannelConfig {
+        attenuation: DB_11,
+        ..Default::default()
+    };
+    let mut mic_chan = AdcChannelDriver::new(&adc, peripherals.pins.gpio4, &mic_config)?;
+    log::info!("Mic ADC ready (GPIO4)");
+
+    // DHT22 temperature/humidity sensor on GPIO2, biasing scatter density,
+    // viewport linger, and the health LED's hue
+    let mut dht_pin = PinDriver::input_output(peripherals.pins.gpio2)?;
+    log::info!("DHT22 ready (GPIO2)");
+
     let interface = I2CDisplayInterface::new(i2c);
     let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
         .into_buffered_graphics_mode();
@@ -471,18 +1160,55 @@ fn main() -> anyhow::Result<()> {
     let mut generation: u32 = 0;
     let mut prev_pop: u32 = 0;
     let mut button_was_pressed = false;
+    let mut press_started_at_us: i64 = 0;
+    let mut last_short_release_at_us: i64 = i64::MIN;
+    // Tiles needing the life rule re-run next `step`; forced fully dirty
+    // after any load/reroll/scatter that edits cells outside of `step`.
+    let mut dirty: u32 = ALL_TILES_DIRTY;
+
+    // Menu-editable live parameters
+    let mut menu = Menu::new();
+    let mut step_delay_ms: u64 = 50;
+    let mut scatter_density: u8 = 0;
+    let mut auto_pan = true;
+    let mut hud_visible = true;
 
     let mut vp = Viewport::new();
 
+    // Environmental sensor state: latest DHT22 reading (if any) and the
+    // extra scatter density it derives, on top of the menu's own setting
+    let mut dht_reading: Option<DhtReading> = None;
+    let mut env_scatter_density: u8 = 0;
+
+    // Rolling FPS estimate (exponential moving average, fixed-point x10)
+    let mut last_frame_us = unsafe { esp_idf_svc::sys::esp_timer_get_time() };
+    let mut fps_ema_x10: u32 = 0;
+
+    // Per-cell age (generations alive), world-space and not double
+    // buffered, kept current by `step` for the WS2812 matrix's age colors
+    let mut age = Box::new([0u8; WORLD_W * WORLD_H]);
+
     // Load initial scene
     let scene = &SCENES[scene_idx];
     (scene.load)(&mut grid_a, &mut rng);
+    grid_a.recompute_all_tile_populations();
     vp.pick_target_seeking(&grid_a, &mut rng);
+    let mut rule = scene.rule;
     log::info!("Scene: {} (gen 0)", scene.name);
 
     loop {
         let current = if use_a { &*grid_a } else { &*grid_b };
 
+        // Rolling FPS estimate: exponential moving average of the inverse
+        // frame time, fixed-point x10 so the HUD can show one decimal.
+        let now_us_frame = unsafe { esp_idf_svc::sys::esp_timer_get_time() };
+        let dt_us = (now_us_frame - last_frame_us).max(1);
+        last_frame_us = now_us_frame;
+        let inst_fps_x10 = (10_000_000 / dt_us as u64) as u32;
+        fps_ema_x10 = (fps_ema_x10 * 9 + inst_fps_x10) / 10;
+
+        let pop = current.population();
+
         // Render viewport region
         display.clear_buffer();
         for sy in 0..SCREEN_H {
@@ -494,40 +1220,183 @@ fn main() -> anyhow::Result<()> {
                 }
             }
         }
+        if hud_visible {
+            draw_hud(
+                &mut display,
+                generation,
+                pop,
+                SCENES[scene_idx].name,
+                fps_ema_x10,
+                dht_reading,
+            );
+        }
+        if menu.open {
+            draw_menu(
+                &mut display,
+                &menu,
+                SCENES[scene_idx].name,
+                step_delay_ms,
+                scatter_density,
+                auto_pan,
+                hud_visible,
+            );
+        }
         display
             .flush()
             .map_err(|e| anyhow::anyhow!("Flush: {:?}", e))?;
 
-        // Population + health LED
-        let pop = current.population();
-        let color = hsv2rgb(health_color(pop, prev_pop));
+        // Mirror the same viewport region onto the age-colored WS2812 matrix
+        let mut matrix_colors = [hsv2rgb(Hsv { hue: 0, sat: 0, val: 0 }); MATRIX_PIXELS];
+        for my in 0..MATRIX_H {
+            for mx in 0..MATRIX_W {
+                let sx = mx * SCREEN_W / MATRIX_W;
+                let sy = my * SCREEN_H / MATRIX_H;
+                let wx = (vp.x as usize + sx) % WORLD_W;
+                let wy = (vp.y as usize + sy) % WORLD_H;
+                let pixel = if current.get(wx, wy) {
+                    hsv2rgb(Hsv {
+                        hue: age_to_hue(age[wy * WORLD_W + wx]),
+                        sat: 255,
+                        val: 40,
+                    })
+                } else {
+                    hsv2rgb(Hsv { hue: 0, sat: 0, val: 0 })
+                };
+                matrix_colors[matrix_serpentine_index(mx, my)] = pixel;
+            }
+        }
+        ws2812_matrix.write(matrix_colors.iter().copied())?;
+
+        // Environmental sensor: poll the DHT22 periodically (it can't settle
+        // any faster) and derive scatter/linger/hue biases from the reading.
+        if generation % DHT_READ_INTERVAL == 0 {
+            if let Some(reading) = read_dht22(&mut dht_pin) {
+                let (density, max_linger) = temp_bias(reading.temp_tenths);
+                env_scatter_density = density;
+                vp.max_linger = max_linger;
+                dht_reading = Some(reading);
+            }
+        }
+        let hue_shift = dht_reading
+            .map(|r| humidity_hue_shift(r.humidity_tenths))
+            .unwrap_or(0);
+
+        // Audio-reactive seeding: sample the mic and run Goertzel on three bands
+        let mic_samples = sample_mic(&mut mic_chan);
+        let bass_energy = goertzel_energy(&mic_samples, BASS_COEFF_Q12).max(0);
+        let mid_energy = goertzel_energy(&mic_samples, MID_COEFF_Q12).max(0);
+        let treble_energy = goertzel_energy(&mic_samples, TREBLE_COEFF_Q12).max(0);
+        let loudness = bass_energy + mid_energy + treble_energy;
+
+        if bass_energy > BASS_BURST_THRESHOLD {
+            let pattern = if rng.next() & 1 == 0 { GLIDER } else { LWSS };
+            let bx = (vp.x as usize + SCREEN_W / 2) % WORLD_W;
+            let by = (vp.y as usize + SCREEN_H / 2) % WORLD_H;
+            let grid = if use_a { &mut *grid_a } else { &mut *grid_b };
+            stamp_pattern(grid, pattern, bx, by);
+            dirty = ALL_TILES_DIRTY;
+        }
+
+        let treble_density = ((treble_energy.min(TREBLE_ENERGY_MAX) * 255
+            / TREBLE_ENERGY_MAX.max(1)) as u8)
+            / 4;
+        if treble_density > 0 {
+            let grid = if use_a { &mut *grid_a } else { &mut *grid_b };
+            scatter_random(grid, &mut rng, treble_density);
+            dirty = ALL_TILES_DIRTY;
+        }
+
+        let mic_brightness = (loudness.min(LOUDNESS_MAX) * 255 / LOUDNESS_MAX.max(1)) as u8;
+
+        let color = hsv2rgb(health_color(pop, prev_pop, mic_brightness, hue_shift));
         ws2812.write([color].iter().copied())?;
         prev_pop = pop;
 
         // Step
-        if use_a {
-            step(&grid_a, &mut grid_b);
+        dirty = if use_a {
+            step(&grid_a, &mut grid_b, generation, dirty, rule, age.as_mut_slice())
         } else {
-            step(&grid_b, &mut grid_a);
-        }
+            step(&grid_b, &mut grid_a, generation, dirty, rule, age.as_mut_slice())
+        };
         use_a = !use_a;
         generation += 1;
 
-        // Pan viewport
-        let current_ref = if use_a { &*grid_a } else { &*grid_b };
-        vp.update(current_ref, &mut rng);
+        // Share our new edges with any neighboring boards.
+        let stepped = if use_a { &*grid_a } else { &*grid_b };
+        broadcast_edges(&esp_now, stepped, generation);
+
+        // Pan viewport (unless the menu has paused it)
+        if auto_pan {
+            let current_ref = if use_a { &*grid_a } else { &*grid_b };
+            vp.update(current_ref, &mut rng);
+        }
 
-        // Button: reroll current scene (edge-triggered, debounced)
+        // Button: gesture-driven menu (short press = cursor, long press =
+        // select/toggle, double press = close), edge-triggered + debounced.
+        let now_us = unsafe { esp_idf_svc::sys::esp_timer_get_time() };
         let pressed = button.is_low();
         if pressed && !button_was_pressed {
-            rng = Rng::from_timer();
-            let scene = &SCENES[scene_idx];
-            let grid = if use_a { &mut *grid_a } else { &mut *grid_b };
-            (scene.load)(grid, &mut rng);
-            generation = 0;
-            vp = Viewport::new();
-            vp.pick_target_seeking(grid, &mut rng);
-            log::info!("Reroll: {} (button)", scene.name);
+            press_started_at_us = now_us;
+        } else if !pressed && button_was_pressed {
+            let held_us = now_us - press_started_at_us;
+            if !menu.open {
+                menu.open = true;
+                menu.cursor = 0;
+                log::info!("Menu opened");
+            } else if held_us >= LONG_PRESS_US {
+                match MENU_ROWS[menu.cursor] {
+                    MenuRow::Scene => {
+                        rng = Rng::from_timer();
+                        scene_idx = (scene_idx + 1) % SCENES.len();
+                        let scene = &SCENES[scene_idx];
+                        let grid = if use_a { &mut *grid_a } else { &mut *grid_b };
+                        (scene.load)(grid, &mut rng);
+                        grid.recompute_all_tile_populations();
+                        dirty = ALL_TILES_DIRTY;
+                        age.fill(0);
+                        generation = 0;
+                        vp = Viewport::new();
+                        vp.pick_target_seeking(grid, &mut rng);
+                        rule = scene.rule;
+                        log::info!("Menu: scene -> {}", scene.name);
+                    }
+                    MenuRow::StepDelay => {
+                        step_delay_ms = match step_delay_ms {
+                            10 => 25,
+                            25 => 50,
+                            50 => 100,
+                            100 => 200,
+                            _ => 10,
+                        };
+                        log::info!("Menu: step delay -> {step_delay_ms} ms");
+                    }
+                    MenuRow::ScatterDensity => {
+                        scatter_density = match scatter_density {
+                            0 => 10,
+                            10 => 25,
+                            25 => 50,
+                            _ => 0,
+                        };
+                        log::info!("Menu: scatter density -> {scatter_density}");
+                    }
+                    MenuRow::AutoPan => {
+                        auto_pan = !auto_pan;
+                        log::info!("Menu: auto-pan -> {auto_pan}");
+                    }
+                    MenuRow::Hud => {
+                        hud_visible = !hud_visible;
+                        log::info!("Menu: HUD -> {hud_visible}");
+                    }
+                }
+                last_short_release_at_us = i64::MIN;
+            } else if now_us.saturating_sub(last_short_release_at_us) <= DOUBLE_PRESS_WINDOW_US {
+                menu.open = false;
+                last_short_release_at_us = i64::MIN;
+                log::info!("Menu closed");
+            } else {
+                menu.cursor = (menu.cursor + 1) % MENU_ROWS.len();
+                last_short_release_at_us = now_us;
+            }
         }
         button_was_pressed = pressed;
 
@@ -537,11 +1406,93 @@ fn main() -> anyhow::Result<()> {
             let scene = &SCENES[scene_idx];
             let grid = if use_a { &mut *grid_a } else { &mut *grid_b };
             (scene.load)(grid, &mut rng);
+            grid.recompute_all_tile_populations();
+            dirty = ALL_TILES_DIRTY;
+            age.fill(0);
             vp = Viewport::new();
             vp.pick_target_seeking(grid, &mut rng);
+            rule = scene.rule;
             log::info!("Scene: {} (gen {})", scene.name, generation);
         }
 
-        thread::sleep(Duration::from_millis(50));
+        // Periodic reseeding: the menu's scatter density plus whatever the
+        // room temperature adds (see `temp_bias`), so a warm room keeps
+        // sprinkling life in without needing another button press.
+        let total_scatter_density = scatter_density.saturating_add(env_scatter_density);
+        if total_scatter_density > 0 && generation % AUTO_SCATTER_INTERVAL == 0 && generation > 0 {
+            let grid = if use_a { &mut *grid_a } else { &mut *grid_b };
+            scatter_random(grid, &mut rng, total_scatter_density);
+            dirty = ALL_TILES_DIRTY;
+        }
+
+        thread::sleep(Duration::from_millis(step_delay_ms));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `generations` steps of the dirty-tile-tracked `step` alongside a
+    /// full-recompute reference (every tile marked dirty, every generation)
+    /// and assert the cells stay identical throughout. A population or
+    /// still-life check alone wouldn't have caught 6aacdc8 (clean tiles not
+    /// aging) or a187790 (diagonal tile-neighbors not dirtied) — both only
+    /// show up as a cell-for-cell mismatch against a brute-force rerun.
+    fn assert_matches_full_recompute(seed: &Grid, rule: Rule, generations: u32) {
+        let mut tracked = Grid {
+            cells: seed.cells,
+            tile_pop: seed.tile_pop,
+        };
+        let mut reference = Grid {
+            cells: seed.cells,
+            tile_pop: seed.tile_pop,
+        };
+        let mut tracked_age = vec![0u8; WORLD_W * WORLD_H];
+        let mut reference_age = vec![0u8; WORLD_W * WORLD_H];
+        let mut dirty = ALL_TILES_DIRTY;
+
+        for generation in 0..generations {
+            let mut next_tracked = Grid::new();
+            dirty = step(&tracked, &mut next_tracked, generation, dirty, rule, &mut tracked_age);
+            tracked = next_tracked;
+
+            let mut next_reference = Grid::new();
+            step(
+                &reference,
+                &mut next_reference,
+                generation,
+                ALL_TILES_DIRTY,
+                rule,
+                &mut reference_age,
+            );
+            reference = next_reference;
+
+            assert_eq!(
+                tracked.cells, reference.cells,
+                "dirty-tile step diverged from full recompute at generation {}",
+                generation + 1
+            );
+        }
+    }
+
+    #[test]
+    fn dirty_tile_step_matches_full_recompute_for_random_soup() {
+        let mut grid = Grid::new();
+        let mut rng = Rng(0xC0FFEE);
+        scatter_random(&mut grid, &mut rng, 64);
+        grid.recompute_all_tile_populations();
+        assert_matches_full_recompute(&grid, RULE_CONWAY, 40);
+    }
+
+    #[test]
+    fn dirty_tile_step_matches_full_recompute_for_glider_crossing_seam() {
+        // Stamped straddling a tile boundary so the glider crosses from one
+        // tile into its neighbors almost immediately, exercising the
+        // dirty-propagation path this test module is meant to guard.
+        let mut grid = Grid::new();
+        stamp_pattern(&mut grid, GLIDER, TILE_W - 2, TILE_H - 2);
+        grid.recompute_all_tile_populations();
+        assert_matches_full_recompute(&grid, RULE_CONWAY, 40);
     }
 }